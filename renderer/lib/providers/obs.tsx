@@ -1,32 +1,92 @@
 import OBSWebSocket from 'obs-websocket-js'
-import { createContext, useContext, useEffect, useState, FC } from 'react'
+import {
+  createContext,
+  useCallback,
+  useContext,
+  useEffect,
+  useRef,
+  useState,
+  FC
+} from 'react'
 
 type ContextTypes = {
   isOBSConnected: boolean
+  currentScene?: string
+  scenes: string[]
+  setScene: (name: string) => Promise<void>
 }
 
 export const OBSContext = createContext<ContextTypes>(undefined!)
 
+// Connection settings, read from `.env.local` (or the environment) at build time.
+const address = process.env.NEXT_PUBLIC_OBS_ADDRESS ?? 'localhost:4444'
+const password = process.env.NEXT_PUBLIC_OBS_PASSWORD ?? ''
+
+// Reconnection delays, in milliseconds.
+const baseDelay = 1000
+const maxDelay = 30000
+
 export const OBSProvider: FC = ({ children }) => {
   const [isOBSConnected, setIsOBSConnected] = useState(false)
+  const [currentScene, setCurrentScene] = useState<string>()
+  const [scenes, setScenes] = useState<string[]>([])
+  const obsRef = useRef<OBSWebSocket>()
 
   useEffect(() => {
     const obs = new OBSWebSocket()
+    obsRef.current = obs
+    let attempts = 0
+    let isDisposed = false
+    let timeout: ReturnType<typeof setTimeout> | undefined
+
+    const fetchScenes = async () => {
+      const data = await obs.send('GetSceneList')
+      setCurrentScene(data['current-scene'])
+      setScenes(data.scenes.map(scene => scene.name))
+    }
+
     const connect = async () => {
-      await obs.connect({ address: 'localhost:4444', password: '' })
-      console.log(`[obs-websocket] Success! Connected & authenticated.`)
-      setIsOBSConnected(true)
+      try {
+        await obs.connect({ address, password })
+        console.log(`[obs-websocket] Success! Connected & authenticated.`)
+        attempts = 0
+        setIsOBSConnected(true)
+      } catch (e) {
+        console.error(e)
+        reconnect()
+        return
+      }
+
+      fetchScenes().catch(err => console.error(err))
     }
 
-    try {
-      connect()
-    } catch (e) {
-      console.error(e)
+    // Exponential backoff with jitter, so a restarting OBS isn't hammered.
+    const reconnect = () => {
+      if (isDisposed || timeout) return
+
+      const backoff = Math.min(maxDelay, baseDelay * 2 ** attempts++)
+      const delay = backoff / 2 + Math.random() * (backoff / 2)
+      console.log(`[obs-websocket] Reconnecting in ${Math.round(delay)}ms.`)
+
+      timeout = setTimeout(() => {
+        timeout = undefined
+        connect()
+      }, delay)
     }
 
     // Register listeners.
     obs.on('SwitchScenes', data => {
       console.log(`[obs-websocket] New Active Scene: ${data['scene-name']}`)
+      setCurrentScene(data['scene-name'])
+    })
+
+    obs.on('ScenesChanged', data => {
+      setScenes(data.scenes.map(scene => scene.name))
+    })
+
+    obs.on('ConnectionClosed', () => {
+      setIsOBSConnected(false)
+      reconnect()
     })
 
     // Register error handlers.
@@ -35,13 +95,23 @@ export const OBSProvider: FC = ({ children }) => {
       console.error('socket error:', err)
     })
 
+    connect()
+
     return () => {
+      isDisposed = true
+      clearTimeout(timeout)
       obs.disconnect()
     }
   }, [])
 
+  const setScene = useCallback(async (name: string) => {
+    await obsRef.current?.send('SetCurrentScene', { 'scene-name': name })
+  }, [])
+
   return (
-    <OBSContext.Provider value={{ isOBSConnected }}>
+    <OBSContext.Provider
+      value={{ isOBSConnected, currentScene, scenes, setScene }}
+    >
       {children}
     </OBSContext.Provider>
   )